@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Tweet, User};
+
+/// Default time-to-live for a cached entry before it's treated as stale and
+/// re-fetched.
+pub const DEFAULT_TTL_SECS: u64 = 6 * 60 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry<T> {
+    value: T,
+    cached_at: u64,
+}
+
+/// On-disk cache of tweets and users, keyed by id, so a repeat
+/// `xint tweet <id>` for the same id doesn't re-bill the API. Thread
+/// reconstruction does not read from or write to this cache.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TweetCache {
+    tweets: HashMap<String, Entry<Tweet>>,
+    users: HashMap<String, Entry<User>>,
+}
+
+impl TweetCache {
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn get_tweet(&self, id: &str, ttl_secs: u64) -> Option<&Tweet> {
+        self.tweets
+            .get(id)
+            .filter(|e| !is_expired(e.cached_at, ttl_secs))
+            .map(|e| &e.value)
+    }
+
+    pub fn put_tweet(&mut self, tweet: Tweet) {
+        self.tweets.insert(
+            tweet.id.clone(),
+            Entry {
+                value: tweet,
+                cached_at: now(),
+            },
+        );
+    }
+
+    pub fn get_user(&self, id: &str, ttl_secs: u64) -> Option<&User> {
+        self.users
+            .get(id)
+            .filter(|e| !is_expired(e.cached_at, ttl_secs))
+            .map(|e| &e.value)
+    }
+
+    pub fn put_user(&mut self, user: User) {
+        self.users.insert(
+            user.id.clone(),
+            Entry {
+                value: user,
+                cached_at: now(),
+            },
+        );
+    }
+
+    pub fn len(&self) -> usize {
+        self.tweets.len() + self.users.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.tweets.clear();
+        self.users.clear();
+    }
+
+    /// Drops entries older than `ttl_secs`, returning how many were removed.
+    pub fn prune(&mut self, ttl_secs: u64) -> usize {
+        let before = self.len();
+        self.tweets.retain(|_, e| !is_expired(e.cached_at, ttl_secs));
+        self.users.retain(|_, e| !is_expired(e.cached_at, ttl_secs));
+        before - self.len()
+    }
+
+    pub fn tweet_count(&self) -> usize {
+        self.tweets.len()
+    }
+
+    pub fn user_count(&self) -> usize {
+        self.users.len()
+    }
+}
+
+fn is_expired(cached_at: u64, ttl_secs: u64) -> bool {
+    now().saturating_sub(cached_at) > ttl_secs
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn default_cache_path(config_dir: &Path) -> PathBuf {
+    config_dir.join("tweet_cache.json")
+}
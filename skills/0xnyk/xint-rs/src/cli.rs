@@ -17,6 +17,9 @@ pub enum Commands {
     #[command(alias = "w")]
     Watch(WatchArgs),
 
+    /// Monitor X in real-time (persistent filtered stream)
+    Stream(StreamArgs),
+
     /// Track follower/following changes over time
     #[command(alias = "followers")]
     Diff(DiffArgs),
@@ -61,6 +64,27 @@ pub enum Commands {
     /// List accounts you follow (OAuth required)
     Following(FollowingArgs),
 
+    /// Follow a user (OAuth required)
+    Follow(FollowArgs),
+
+    /// Unfollow a user (OAuth required)
+    Unfollow(UnfollowArgs),
+
+    /// Post a new tweet (OAuth required)
+    Post(PostArgs),
+
+    /// Reply to a tweet (OAuth required)
+    Reply(ReplyArgs),
+
+    /// Quote-tweet a tweet (OAuth required)
+    Quote(QuoteArgs),
+
+    /// Retweet a tweet (OAuth required)
+    Retweet(RetweetArgs),
+
+    /// Undo a retweet (OAuth required)
+    Unretweet(UnretweetArgs),
+
     /// Fetch trending topics
     #[command(alias = "tr")]
     Trends(TrendsArgs),
@@ -94,6 +118,9 @@ pub enum Commands {
     /// Start MCP server for AI agents (Claude, OpenAI)
     #[command(alias = "mcp-server")]
     Mcp(McpArgs),
+
+    /// Interactive investigation session (persistent cache + command loop)
+    Repl(ReplArgs),
 }
 
 // ---------------------------------------------------------------------------
@@ -180,6 +207,10 @@ pub struct SearchArgs {
     /// Markdown output
     #[arg(long)]
     pub markdown: bool,
+
+    /// RSS 2.0 feed output, suitable for piping to a feed reader
+    #[arg(long)]
+    pub rss: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -216,6 +247,37 @@ pub struct WatchArgs {
     pub jsonl: bool,
 }
 
+// ---------------------------------------------------------------------------
+// Stream
+// ---------------------------------------------------------------------------
+
+#[derive(Parser)]
+pub struct StreamArgs {
+    /// Stream rule to add (can be passed multiple times)
+    #[arg(long = "rule", short = 'r')]
+    pub rules: Vec<String>,
+
+    /// Remove a stream rule by its id
+    #[arg(long)]
+    pub remove_rule: Vec<String>,
+
+    /// List currently active stream rules and exit
+    #[arg(long)]
+    pub list_rules: bool,
+
+    /// POST new tweets to this URL
+    #[arg(long)]
+    pub webhook: Option<String>,
+
+    /// Output JSONL
+    #[arg(long)]
+    pub jsonl: bool,
+
+    /// Suppress per-event headers
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+}
+
 // ---------------------------------------------------------------------------
 // Diff
 // ---------------------------------------------------------------------------
@@ -284,6 +346,14 @@ pub struct ThreadArgs {
     /// Pages to fetch
     #[arg(long, default_value = "2")]
     pub pages: u32,
+
+    /// How many ancestor/reply hops to walk when reconstructing the conversation
+    #[arg(long, default_value = "5")]
+    pub depth: u32,
+
+    /// RSS 2.0 feed output, suitable for piping to a feed reader
+    #[arg(long)]
+    pub rss: bool,
 }
 
 #[derive(Parser)]
@@ -302,6 +372,10 @@ pub struct ProfileArgs {
     /// JSON output
     #[arg(long)]
     pub json: bool,
+
+    /// RSS 2.0 feed output, suitable for piping to a feed reader
+    #[arg(long)]
+    pub rss: bool,
 }
 
 #[derive(Parser)]
@@ -312,6 +386,10 @@ pub struct TweetArgs {
     /// JSON output
     #[arg(long)]
     pub json: bool,
+
+    /// Colorize terminal output: auto, always, or never
+    #[arg(long, default_value = "auto")]
+    pub color: String,
 }
 
 #[derive(Parser)]
@@ -418,12 +496,20 @@ pub struct LikesArgs {
 pub struct LikeArgs {
     /// Tweet ID to like
     pub tweet_id: String,
+
+    /// JSON output
+    #[arg(long)]
+    pub json: bool,
 }
 
 #[derive(Parser)]
 pub struct UnlikeArgs {
     /// Tweet ID to unlike
     pub tweet_id: String,
+
+    /// JSON output
+    #[arg(long)]
+    pub json: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -444,6 +530,94 @@ pub struct FollowingArgs {
     pub json: bool,
 }
 
+#[derive(Parser)]
+pub struct FollowArgs {
+    /// Username to follow
+    pub username: String,
+
+    /// JSON output
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Parser)]
+pub struct UnfollowArgs {
+    /// Username to unfollow
+    pub username: String,
+
+    /// JSON output
+    #[arg(long)]
+    pub json: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Write actions: post, reply, quote, retweet
+// ---------------------------------------------------------------------------
+
+#[derive(Parser)]
+pub struct PostArgs {
+    /// Tweet text (reads from stdin if omitted)
+    pub text: Option<String>,
+
+    /// Post as a reply to this tweet ID
+    #[arg(long)]
+    pub reply_to: Option<String>,
+
+    /// Quote-tweet this tweet ID
+    #[arg(long)]
+    pub quote: Option<String>,
+
+    /// JSON output
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Parser)]
+pub struct ReplyArgs {
+    /// Tweet ID to reply to
+    pub tweet_id: String,
+
+    /// Reply text (reads from stdin if omitted)
+    pub text: Option<String>,
+
+    /// JSON output
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Parser)]
+pub struct QuoteArgs {
+    /// Tweet ID to quote
+    pub tweet_id: String,
+
+    /// Quote-tweet text (reads from stdin if omitted)
+    pub text: Option<String>,
+
+    /// JSON output
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Parser)]
+pub struct RetweetArgs {
+    /// Tweet ID to retweet
+    pub tweet_id: String,
+
+    /// JSON output
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Parser)]
+pub struct UnretweetArgs {
+    /// Tweet ID to undo the retweet of
+    pub tweet_id: String,
+
+    /// JSON output
+    #[arg(long)]
+    pub json: bool,
+}
+
 // ---------------------------------------------------------------------------
 // Trends
 // ---------------------------------------------------------------------------
@@ -468,6 +642,26 @@ pub struct TrendsArgs {
     /// List known locations
     #[arg(long)]
     pub locations: bool,
+
+    /// Derive trends locally from already-fetched tweets instead of the trends API
+    #[arg(long)]
+    pub local: bool,
+
+    /// Source corpus for --local: a JSONL file of tweets (defaults to data/exports/latest.jsonl)
+    #[arg(long)]
+    pub from_file: Option<String>,
+
+    /// Newline-delimited file of blocked terms for --local, used in place of a small built-in list
+    #[arg(long)]
+    pub blocklist_file: Option<String>,
+
+    /// With --local, keep tailing --from-file and re-rank on an interval instead of computing once
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Re-rank interval for --watch: 30s, 1m, 5m
+    #[arg(long, default_value = "1m")]
+    pub watch_interval: String,
 }
 
 // ---------------------------------------------------------------------------
@@ -502,7 +696,7 @@ pub struct AnalyzeArgs {
 
 #[derive(Parser)]
 pub struct CostsArgs {
-    /// Subcommand: today, week, month, all, budget, reset
+    /// Subcommand: today, week, month, all, budget, reset, pool
     pub subcommand: Option<Vec<String>>,
 }
 
@@ -536,7 +730,7 @@ pub struct AuthArgs {
 
 #[derive(Parser)]
 pub struct CacheArgs {
-    /// Subcommand: clear
+    /// Subcommand: list, size, clear, prune
     pub subcommand: Option<String>,
 }
 
@@ -614,8 +808,19 @@ pub struct McpArgs {
     /// Run in SSE mode (HTTP server)
     #[arg(long)]
     pub sse: bool,
-    
+
     /// Port for SSE mode (default: 3000)
     #[arg(long, default_value = "3000")]
     pub port: u16,
 }
+
+// ---------------------------------------------------------------------------
+// Repl
+// ---------------------------------------------------------------------------
+
+#[derive(Parser)]
+pub struct ReplArgs {
+    /// Path to the history file (default: <config dir>/repl_history)
+    #[arg(long)]
+    pub history_file: Option<String>,
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use reqwest::{Client, Method, Response};
+use serde::Serialize;
+
+use crate::config::Config;
+
+/// Thin wrapper around a shared `reqwest::Client` so connection pooling and
+/// bearer-token rotation on rate limits live in one place instead of each
+/// `api::twitter` call reimplementing retry logic.
+pub struct XClient {
+    http: Client,
+}
+
+impl XClient {
+    pub fn new() -> Result<Self> {
+        let http = Client::builder()
+            .user_agent("xint-rs")
+            .timeout(Duration::from_secs(30))
+            .build()
+            .context("failed to build HTTP client")?;
+        Ok(XClient { http })
+    }
+
+    /// Sends a bearer-authenticated request, recording whatever rate-limit
+    /// headers come back against the token pool. If the response is a 429,
+    /// transparently retries once against the next-best pooled token (if
+    /// rotating actually picked a different one) rather than surfacing the
+    /// failure straight to the caller.
+    pub async fn send_authorized(
+        &self,
+        config: &Config,
+        method: Method,
+        url: &str,
+    ) -> Result<Response> {
+        let token = config.require_bearer_token()?;
+        let response = self.authorized_once(&token, method.clone(), url).await?;
+        self.record_limits(config, &token, &response)?;
+
+        if response.status().as_u16() != 429 {
+            return Ok(response);
+        }
+
+        let retry_token = config.require_bearer_token()?;
+        if retry_token == token {
+            return Ok(response); // no other token has headroom left
+        }
+
+        let retried = self.authorized_once(&retry_token, method, url).await?;
+        self.record_limits(config, &retry_token, &retried)?;
+        Ok(retried)
+    }
+
+    async fn authorized_once(&self, token: &str, method: Method, url: &str) -> Result<Response> {
+        Ok(self.http.request(method, url).bearer_auth(token).send().await?)
+    }
+
+    fn record_limits(&self, config: &Config, token: &str, response: &Response) -> Result<()> {
+        let remaining = if response.status().as_u16() == 429 {
+            0
+        } else {
+            header_u32(response, "x-rate-limit-remaining").unwrap_or(u32::MAX)
+        };
+        let reset_at = header_i64(response, "x-rate-limit-reset").unwrap_or(0);
+        config.record_rate_limit(token, remaining, reset_at)
+    }
+
+    pub async fn post_webhook<T: Serialize + ?Sized>(&self, url: &str, payload: &T) -> Result<()> {
+        self.http.post(url).json(payload).send().await?.error_for_status()?;
+        Ok(())
+    }
+}
+
+fn header_u32(response: &Response, name: &str) -> Option<u32> {
+    response.headers().get(name)?.to_str().ok()?.parse().ok()
+}
+
+fn header_i64(response: &Response, name: &str) -> Option<i64> {
+    response.headers().get(name)?.to_str().ok()?.parse().ok()
+}
@@ -0,0 +1,49 @@
+use std::io::IsTerminal;
+use std::str::FromStr;
+
+/// How `format::format_tweet_terminal` should decide whether to emit ANSI
+/// color codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+impl FromStr for ColorMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(ColorMode::Auto),
+            "always" => Ok(ColorMode::Always),
+            "never" => Ok(ColorMode::Never),
+            other => anyhow::bail!("invalid --color value `{other}`, expected auto, always, or never"),
+        }
+    }
+}
+
+/// Resolves a `ColorMode` against whether stdout is currently a TTY.
+pub fn should_colorize(mode: ColorMode) -> bool {
+    match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => std::io::stdout().is_terminal(),
+    }
+}
+
+pub const DIM: &str = "\x1b[2m";
+pub const BOLD: &str = "\x1b[1m";
+pub const BLUE: &str = "\x1b[34m";
+pub const CYAN: &str = "\x1b[36m";
+pub const GREEN: &str = "\x1b[32m";
+pub const RESET: &str = "\x1b[0m";
+
+/// Wraps `text` in `code` when `enabled`, otherwise returns it unchanged.
+pub fn paint(enabled: bool, code: &str, text: &str) -> String {
+    if enabled {
+        format!("{code}{text}{RESET}")
+    } else {
+        text.to_string()
+    }
+}
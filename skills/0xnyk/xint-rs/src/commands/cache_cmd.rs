@@ -0,0 +1,38 @@
+use anyhow::Result;
+
+use crate::cache::{self, TweetCache};
+use crate::cli::CacheArgs;
+use crate::config::Config;
+
+pub fn run(args: &CacheArgs, config: &Config) -> Result<()> {
+    let path = cache::default_cache_path(&config.config_dir());
+    let mut cache = TweetCache::load(&path);
+
+    match args.subcommand.as_deref() {
+        Some("clear") => {
+            cache.clear();
+            cache.save(&path)?;
+            println!("Cache cleared.");
+        }
+        Some("prune") => {
+            let removed = cache.prune(cache::DEFAULT_TTL_SECS);
+            cache.save(&path)?;
+            println!("Removed {} expired entries.", removed);
+        }
+        Some("size") => {
+            println!("{} entries ({} tweets, {} users)", cache.len(), cache.tweet_count(), cache.user_count());
+        }
+        Some("list") | None => {
+            if cache.is_empty() {
+                println!("Cache is empty.");
+            } else {
+                println!("{} tweets, {} users cached at {}", cache.tweet_count(), cache.user_count(), path.display());
+            }
+        }
+        Some(other) => {
+            println!("Unknown cache subcommand: {other}. Try list, size, clear, or prune.");
+        }
+    }
+
+    Ok(())
+}
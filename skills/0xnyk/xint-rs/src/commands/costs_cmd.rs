@@ -0,0 +1,40 @@
+use anyhow::Result;
+
+use crate::cli::CostsArgs;
+use crate::config::Config;
+use crate::costs;
+
+pub fn run(args: &CostsArgs, config: &Config) -> Result<()> {
+    let path = config.costs_path();
+    let sub = args.subcommand.clone().unwrap_or_default();
+
+    match sub.first().map(String::as_str) {
+        None => println!("{}", costs::get_cost_summary(&path, "today")),
+        Some(period @ ("today" | "week" | "month" | "all")) => {
+            println!("{}", costs::get_cost_summary(&path, period));
+        }
+        Some("budget") => match sub.get(1).map(|s| s.parse::<f64>()) {
+            Some(Ok(limit)) => {
+                costs::set_budget(&path, limit);
+                println!("Budget set to ${limit:.2}.");
+            }
+            _ => {
+                let status = costs::check_budget(&path);
+                println!("Budget: ${:.2} spent of ${:.2}", status.spent, status.limit);
+            }
+        },
+        Some("pool") => {
+            let pool = config.token_pool();
+            println!("{}", pool.health_report(crate::config::now_unix()));
+        }
+        Some("reset") => {
+            let _ = std::fs::remove_file(&path);
+            println!("Cost ledger reset.");
+        }
+        Some(other) => {
+            println!("Unknown costs subcommand: {other}. Try today, week, month, all, budget, reset, or pool.");
+        }
+    }
+
+    Ok(())
+}
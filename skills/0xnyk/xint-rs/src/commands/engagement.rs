@@ -0,0 +1,223 @@
+use std::io::Read;
+
+use anyhow::{bail, Result};
+
+use crate::api::twitter;
+use crate::cli::{
+    FollowArgs, LikeArgs, PostArgs, QuoteArgs, ReplyArgs, RetweetArgs, UnfollowArgs, UnlikeArgs,
+    UnretweetArgs,
+};
+use crate::client::XClient;
+use crate::config::Config;
+use crate::costs;
+use crate::format;
+
+const MAX_TWEET_LEN: usize = 280;
+
+pub async fn run_post(args: &PostArgs, config: &Config, client: &XClient) -> Result<()> {
+    let text = resolve_text(args.text.clone())?;
+    let token = config.require_bearer_token()?;
+
+    let quote_url = args
+        .quote
+        .as_ref()
+        .map(|id| format!("https://x.com/i/web/status/{}", id));
+
+    run_hook(config.pre_post_hook(), &text)?;
+
+    let tweet = twitter::post_tweet(
+        client,
+        &token,
+        &text,
+        args.reply_to.as_deref(),
+        quote_url.as_deref(),
+    )
+    .await?;
+    costs::track_cost(&config.costs_path(), "post", "/2/tweets", 1);
+
+    run_hook(config.post_post_hook(), &tweet.id)?;
+
+    print_created(&tweet, args.json, config, client).await
+}
+
+/// Runs a user-configured `pre_post_hook`/`post_post_hook` shell command,
+/// passing `payload` on stdin so hooks can lint or log outgoing content
+/// without the CLI needing to know what they do.
+fn run_hook(hook: Option<&str>, payload: &str) -> Result<()> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let Some(hook) = hook else {
+        return Ok(());
+    };
+
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(hook)
+        .stdin(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(payload.as_bytes())?;
+        drop(stdin);
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("hook `{hook}` exited with status {status}");
+    }
+    Ok(())
+}
+
+pub async fn run_reply(args: &ReplyArgs, config: &Config, client: &XClient) -> Result<()> {
+    let text = resolve_text(args.text.clone())?;
+    let token = config.require_bearer_token()?;
+
+    let tweet = twitter::post_tweet(client, &token, &text, Some(&args.tweet_id), None).await?;
+    costs::track_cost(&config.costs_path(), "reply", "/2/tweets", 1);
+
+    print_created(&tweet, args.json, config, client).await
+}
+
+pub async fn run_quote(args: &QuoteArgs, config: &Config, client: &XClient) -> Result<()> {
+    let text = resolve_text(args.text.clone())?;
+    let token = config.require_bearer_token()?;
+
+    let quote_url = format!("https://x.com/i/web/status/{}", args.tweet_id);
+    let tweet = twitter::post_tweet(client, &token, &text, None, Some(&quote_url)).await?;
+    costs::track_cost(&config.costs_path(), "quote", "/2/tweets", 1);
+
+    print_created(&tweet, args.json, config, client).await
+}
+
+pub async fn run_retweet(args: &RetweetArgs, config: &Config, client: &XClient) -> Result<()> {
+    let token = config.require_bearer_token()?;
+    let user_id = resolve_user_id(config, client, &token).await?;
+
+    let result = twitter::retweet(client, &token, &user_id, &args.tweet_id).await?;
+    costs::track_cost(&config.costs_path(), "retweet", "/2/users/:id/retweets", 1);
+
+    print_confirmation(&result, args.json, &format!("Retweeted {}", args.tweet_id))
+}
+
+pub async fn run_unretweet(args: &UnretweetArgs, config: &Config, client: &XClient) -> Result<()> {
+    let token = config.require_bearer_token()?;
+    let user_id = resolve_user_id(config, client, &token).await?;
+
+    let result = twitter::unretweet(client, &token, &user_id, &args.tweet_id).await?;
+    costs::track_cost(&config.costs_path(), "unretweet", "/2/users/:id/retweets", 1);
+
+    print_confirmation(&result, args.json, &format!("Removed retweet of {}", args.tweet_id))
+}
+
+pub async fn run_like(args: &LikeArgs, config: &Config, client: &XClient) -> Result<()> {
+    let token = config.require_bearer_token()?;
+    let user_id = resolve_user_id(config, client, &token).await?;
+
+    let result = twitter::like(client, &token, &user_id, &args.tweet_id).await?;
+    costs::track_cost(&config.costs_path(), "like", "/2/users/:id/likes", 1);
+
+    print_confirmation(&result, args.json, &format!("Liked {}", args.tweet_id))
+}
+
+pub async fn run_unlike(args: &UnlikeArgs, config: &Config, client: &XClient) -> Result<()> {
+    let token = config.require_bearer_token()?;
+    let user_id = resolve_user_id(config, client, &token).await?;
+
+    let result = twitter::unlike(client, &token, &user_id, &args.tweet_id).await?;
+    costs::track_cost(&config.costs_path(), "unlike", "/2/users/:id/likes", 1);
+
+    print_confirmation(&result, args.json, &format!("Unliked {}", args.tweet_id))
+}
+
+pub async fn run_follow(args: &FollowArgs, config: &Config, client: &XClient) -> Result<()> {
+    let token = config.require_bearer_token()?;
+    let user_id = resolve_user_id(config, client, &token).await?;
+    let username = args.username.trim_start_matches('@');
+
+    let target = twitter::get_user_by_username(client, &token, username).await?;
+    let result = twitter::follow(client, &token, &user_id, &target.id).await?;
+    costs::track_cost(&config.costs_path(), "follow", "/2/users/:source/following", 2);
+
+    print_confirmation(&result, args.json, &format!("Followed @{}", username))
+}
+
+pub async fn run_unfollow(args: &UnfollowArgs, config: &Config, client: &XClient) -> Result<()> {
+    let token = config.require_bearer_token()?;
+    let user_id = resolve_user_id(config, client, &token).await?;
+    let username = args.username.trim_start_matches('@');
+
+    let target = twitter::get_user_by_username(client, &token, username).await?;
+    let result = twitter::unfollow(client, &token, &user_id, &target.id).await?;
+    costs::track_cost(&config.costs_path(), "unfollow", "/2/users/:source/following", 2);
+
+    print_confirmation(&result, args.json, &format!("Unfollowed @{}", username))
+}
+
+fn resolve_text(text: Option<String>) -> Result<String> {
+    let text = match text {
+        Some(t) => t,
+        None => {
+            let mut buf = String::new();
+            std::io::stdin().read_to_string(&mut buf)?;
+            buf
+        }
+    };
+    let text = text.trim().to_string();
+
+    if text.is_empty() {
+        bail!("tweet text must not be empty");
+    }
+    if text.chars().count() > MAX_TWEET_LEN {
+        bail!(
+            "tweet text is {} characters, over the {}-character limit",
+            text.chars().count(),
+            MAX_TWEET_LEN
+        );
+    }
+
+    Ok(text)
+}
+
+async fn resolve_user_id(config: &Config, client: &XClient, token: &str) -> Result<String> {
+    if let Some(id) = config.cached_user_id() {
+        return Ok(id);
+    }
+    let me = twitter::get_me(client, token).await?;
+    config.cache_user_id(&me.id)?;
+    Ok(me.id)
+}
+
+async fn print_created(tweet: &twitter::CreatedTweet, json: bool, config: &Config, client: &XClient) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(tweet)?);
+        return Ok(());
+    }
+
+    let token = config.require_bearer_token()?;
+    match twitter::get_tweet(client, &token, &tweet.id).await {
+        Ok(Some(full)) => {
+            let colorize = crate::color::should_colorize(crate::color::ColorMode::Auto);
+            println!("{}", format::format_tweet_terminal(&full, None, true, colorize));
+        }
+        _ => {
+            // Fetch failed (e.g. eventual-consistency lag right after posting) —
+            // fall back to the bare confirmation rather than erroring the post itself.
+            println!("Posted {}", tweet.id);
+        }
+    }
+    println!("https://x.com/i/web/status/{}", tweet.id);
+    Ok(())
+}
+
+/// Prints a short confirmation line, or `result` (the raw API response) when
+/// `json` is set — mirrors `print_created`'s `--json` ergonomics for the
+/// simpler engagement actions that don't return a created tweet.
+fn print_confirmation(result: &serde_json::Value, json: bool, message: &str) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(result)?);
+    } else {
+        println!("{message}");
+    }
+    Ok(())
+}
@@ -23,7 +23,10 @@ pub async fn run(args: &ProfileArgs, config: &Config, client: &XClient) -> Resul
         tweets.len() as u64 + 1,
     );
 
-    if args.json {
+    if args.rss {
+        let title = format!("@{} on X", username);
+        println!("{}", format::format_rss(&title, &tweets));
+    } else if args.json {
         let output = serde_json::json!({
             "user": user,
             "tweets": tweets,
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use clap::Parser;
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+
+use crate::cli::{LikeArgs, ProfileArgs, ReplArgs, SearchArgs, ThreadArgs};
+use crate::client::XClient;
+use crate::commands;
+use crate::config::Config;
+use crate::format;
+
+/// Maps the short display index printed next to a tweet (e.g. `[3]`) back to
+/// its real tweet id, so follow-up commands in the same session can refer to
+/// `3` instead of retyping an 18-digit id.
+#[derive(Default)]
+struct TweetCache {
+    by_index: HashMap<usize, String>,
+    next: usize,
+}
+
+impl TweetCache {
+    fn remember(&mut self, tweet_id: &str) -> usize {
+        let idx = self.next;
+        self.next += 1;
+        self.by_index.insert(idx, tweet_id.to_string());
+        idx
+    }
+
+    fn resolve<'a>(&'a self, token: &'a str) -> &'a str {
+        match token.parse::<usize>() {
+            Ok(idx) => self.by_index.get(&idx).map(String::as_str).unwrap_or(token),
+            Err(_) => token,
+        }
+    }
+}
+
+pub async fn run(args: &ReplArgs, config: &Config, client: &XClient) -> Result<()> {
+    let history_path = args
+        .history_file
+        .clone()
+        .unwrap_or_else(|| config.config_dir().join("repl_history").to_string_lossy().to_string());
+
+    let mut cache = TweetCache::default();
+    let mut editor = DefaultEditor::new()?;
+    let _ = editor.load_history(&history_path);
+
+    println!("xint repl — type a command (search, thread, profile, like) or `exit`.");
+
+    loop {
+        let line = match editor.readline("xint> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Eof) | Err(ReadlineError::Interrupted) => break,
+            Err(err) => return Err(err.into()),
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line == "exit" || line == "quit" {
+            break;
+        }
+
+        let _ = editor.add_history_entry(line);
+
+        let tokens: Vec<String> = line.split_whitespace().map(String::from).collect();
+        if let Err(err) = dispatch(&tokens, &mut cache, config, client).await {
+            eprintln!("error: {err}");
+        }
+    }
+
+    let _ = editor.save_history(&history_path);
+    Ok(())
+}
+
+async fn dispatch(tokens: &[String], cache: &mut TweetCache, config: &Config, client: &XClient) -> Result<()> {
+    let (cmd, rest) = match tokens.split_first() {
+        Some(pair) => pair,
+        None => return Ok(()),
+    };
+
+    match cmd.as_str() {
+        "search" | "s" => {
+            let args = SearchArgs::parse_from(std::iter::once("search".to_string()).chain(rest.iter().cloned()));
+            let tweets = commands::search::run_captured(&args, config, client).await?;
+            let colorize = crate::color::should_colorize(crate::color::ColorMode::Auto);
+            for tweet in &tweets {
+                let idx = cache.remember(&tweet.id);
+                println!("{}", format::format_tweet_terminal(tweet, Some(idx), true, colorize));
+            }
+        }
+        "thread" | "t" => {
+            let tweet_id = rest.first().map(|t| cache.resolve(t).to_string()).unwrap_or_default();
+            let args = ThreadArgs::parse_from(["thread".to_string(), tweet_id]);
+            let colorize = crate::color::should_colorize(crate::color::ColorMode::Auto);
+            if let Some(conversation) = commands::thread::run_captured(&args, config, client).await? {
+                for ancestor in &conversation.ancestors {
+                    let idx = cache.remember(&ancestor.id);
+                    println!("{}\n", format::format_tweet_terminal(ancestor, Some(idx), false, colorize));
+                }
+                let idx = cache.remember(&conversation.root.id);
+                println!("{}\n", format::format_tweet_terminal(&conversation.root, Some(idx), true, colorize));
+                for reply in &conversation.replies {
+                    let idx = cache.remember(&reply.id);
+                    println!("{}\n", format::format_tweet_terminal(reply, Some(idx), true, colorize));
+                }
+            } else {
+                println!("No tweets found in thread.");
+            }
+        }
+        "profile" | "p" => {
+            let who = rest.first().map(|t| cache.resolve(t).to_string()).unwrap_or_default();
+            let args = ProfileArgs::parse_from(["profile".to_string(), who]);
+            commands::profile::run(&args, config, client).await?;
+        }
+        "like" => {
+            let tweet_id = rest.first().map(|t| cache.resolve(t).to_string()).unwrap_or_default();
+            let args = LikeArgs { tweet_id, json: false };
+            commands::engagement::run_like(&args, config, client).await?;
+        }
+        other => {
+            eprintln!("unknown command: {other}");
+        }
+    }
+
+    Ok(())
+}
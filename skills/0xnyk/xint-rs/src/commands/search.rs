@@ -0,0 +1,80 @@
+use anyhow::Result;
+
+use crate::api::twitter;
+use crate::cli::SearchArgs;
+use crate::client::XClient;
+use crate::config::Config;
+use crate::costs;
+use crate::format;
+use crate::models::Tweet;
+
+pub async fn run(args: &SearchArgs, config: &Config, client: &XClient) -> Result<()> {
+    let tweets = fetch(args, config, client).await?;
+
+    if args.rss {
+        let title = format!("xint search: {}", args.query.join(" "));
+        println!("{}", format::format_rss(&title, &tweets));
+    } else if args.json {
+        println!("{}", serde_json::to_string_pretty(&tweets)?);
+    } else if args.jsonl {
+        for tweet in &tweets {
+            println!("{}", serde_json::to_string(tweet)?);
+        }
+    } else {
+        let colorize = crate::color::should_colorize(crate::color::ColorMode::Auto);
+        for tweet in &tweets {
+            println!("{}\n", format::format_tweet_terminal(tweet, None, true, colorize));
+        }
+    }
+
+    Ok(())
+}
+
+/// Same search as `run`, but returns the fetched tweets instead of printing
+/// them, so callers like `repl` can render them through their own
+/// index-tracking cache.
+pub async fn run_captured(args: &SearchArgs, config: &Config, client: &XClient) -> Result<Vec<Tweet>> {
+    fetch(args, config, client).await
+}
+
+async fn fetch(args: &SearchArgs, config: &Config, client: &XClient) -> Result<Vec<Tweet>> {
+    let token = config.require_bearer_token()?;
+
+    let query = if let Some(from) = &args.from {
+        format!("from:{} {}", from, args.query.join(" "))
+    } else {
+        args.query.join(" ")
+    };
+
+    let opts = twitter::SearchOpts {
+        pages: args.pages,
+        since: args.since.clone(),
+        until: args.until.clone(),
+        full_archive: args.full,
+        exclude_replies: args.no_replies,
+        exclude_retweets: args.no_retweets,
+    };
+
+    let mut tweets = twitter::search_tweets(client, &token, &query, &opts).await?;
+    costs::track_cost(
+        &config.costs_path(),
+        "search",
+        "/2/tweets/search/recent",
+        tweets.len() as u64,
+    );
+
+    tweets.retain(|t| t.metrics.likes >= args.min_likes && t.metrics.impressions >= args.min_impressions);
+    sort_tweets(&mut tweets, &args.sort);
+    tweets.truncate(args.limit);
+
+    Ok(tweets)
+}
+
+fn sort_tweets(tweets: &mut [Tweet], sort: &str) {
+    match sort {
+        "impressions" => tweets.sort_by(|a, b| b.metrics.impressions.cmp(&a.metrics.impressions)),
+        "retweets" => tweets.sort_by(|a, b| b.metrics.retweets.cmp(&a.metrics.retweets)),
+        "recent" => tweets.sort_by(|a, b| b.created_at.cmp(&a.created_at)),
+        _ => tweets.sort_by(|a, b| b.metrics.likes.cmp(&a.metrics.likes)),
+    }
+}
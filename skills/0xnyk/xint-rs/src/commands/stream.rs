@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::api::twitter;
+use crate::cli::StreamArgs;
+use crate::client::XClient;
+use crate::config::Config;
+use crate::costs;
+use crate::format;
+
+/// Caps how long we remember a tweet id for dedup purposes; the filtered
+/// stream can redeliver a tweet across a reconnect, and this keeps memory
+/// bounded for long-running sessions.
+const SEEN_CAPACITY: usize = 10_000;
+
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+pub async fn run(args: &StreamArgs, config: &Config, client: &XClient) -> Result<()> {
+    let token = config.require_bearer_token()?;
+
+    if args.list_rules {
+        let rules = twitter::list_stream_rules(client, &token).await?;
+        for rule in rules {
+            println!("{}\t{}", rule.id, rule.value);
+        }
+        return Ok(());
+    }
+
+    for rule_id in &args.remove_rule {
+        twitter::delete_stream_rule(client, &token, rule_id).await?;
+        eprintln!("Removed rule {}", rule_id);
+    }
+
+    if !args.rules.is_empty() {
+        let added = twitter::add_stream_rules(client, &token, &args.rules).await?;
+        for rule in &added {
+            eprintln!("Added rule {}: {}", rule.id, rule.value);
+        }
+    }
+
+    eprintln!("Connecting to filtered stream...");
+
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut backoff = INITIAL_BACKOFF;
+
+    loop {
+        let mut response = match twitter::open_filtered_stream(client, &token).await {
+            Ok(response) => response,
+            Err(err) => {
+                eprintln!("Stream connect failed ({err}), retrying in {:?}", backoff);
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+                continue;
+            }
+        };
+
+        // Holds whatever trailing bytes didn't end in a newline yet, as raw
+        // bytes rather than a `String`: a multibyte UTF-8 character (e.g. an
+        // emoji) can legitimately arrive split across two TCP chunks, and
+        // decoding each chunk independently would corrupt it into
+        // replacement characters. Buffering raw bytes and only decoding once
+        // a full line has accumulated avoids that.
+        let mut pending: Vec<u8> = Vec::new();
+        let mut got_any_chunk = false;
+
+        loop {
+            match response.chunk().await {
+                Ok(Some(bytes)) => {
+                    got_any_chunk = true;
+                    backoff = INITIAL_BACKOFF;
+
+                    pending.extend_from_slice(&bytes);
+
+                    while let Some(newline_at) = pending.iter().position(|&b| b == b'\n') {
+                        let line_bytes: Vec<u8> = pending.drain(..=newline_at).collect();
+                        let line = String::from_utf8_lossy(&line_bytes[..line_bytes.len() - 1])
+                            .trim()
+                            .to_string();
+
+                        if line.is_empty() {
+                            continue; // keepalive
+                        }
+
+                        handle_line(&line, args, config, client, &mut seen).await?;
+                    }
+                }
+                Ok(None) => break, // EOF, fall through to reconnect
+                Err(err) => {
+                    eprintln!("Stream read error: {err}");
+                    break;
+                }
+            }
+        }
+
+        if !got_any_chunk {
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+        eprintln!("Stream disconnected, reconnecting in {:?}...", backoff);
+        tokio::time::sleep(backoff).await;
+    }
+}
+
+async fn handle_line(
+    line: &str,
+    args: &StreamArgs,
+    config: &Config,
+    client: &XClient,
+    seen: &mut HashSet<String>,
+) -> Result<()> {
+    match serde_json::from_str::<twitter::StreamEvent>(line) {
+        Ok(twitter::StreamEvent::Tweet(tweet)) => {
+            if !seen.insert(tweet.id.clone()) {
+                return Ok(());
+            }
+            if seen.len() > SEEN_CAPACITY {
+                seen.clear();
+                seen.insert(tweet.id.clone());
+            }
+
+            costs::track_cost(&config.costs_path(), "stream", "/2/tweets/search/stream", 1);
+
+            if args.jsonl {
+                println!("{}", serde_json::to_string(&tweet)?);
+            } else if !args.quiet {
+                let colorize = crate::color::should_colorize(crate::color::ColorMode::Auto);
+                println!("{}", format::format_tweet_terminal(&tweet, None, true, colorize));
+            }
+
+            if let Some(webhook) = &args.webhook {
+                if let Err(err) = client.post_webhook(webhook, &tweet).await {
+                    eprintln!("Webhook post failed: {err}");
+                }
+            }
+        }
+        Ok(twitter::StreamEvent::Delete(id)) => {
+            seen.remove(&id);
+            if !args.quiet {
+                eprintln!("Tweet {} was deleted", id);
+            }
+        }
+        Err(err) => {
+            eprintln!("Skipping unparseable stream line: {err}");
+        }
+    }
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anyhow::Result;
 
-use crate::api::twitter;
+use crate::api::twitter::{self, Conversation};
 use crate::cli::ThreadArgs;
 use crate::client::XClient;
 use crate::config::Config;
@@ -8,31 +8,53 @@ use crate::costs;
 use crate::format;
 
 pub async fn run(args: &ThreadArgs, config: &Config, client: &XClient) -> Result<()> {
+    let conversation = match fetch(args, config, client).await? {
+        Some(conversation) => conversation,
+        None => {
+            println!("No tweets found in thread.");
+            return Ok(());
+        }
+    };
+
+    if args.rss {
+        let title = format!("Thread {}", args.tweet_id);
+        let mut tweets = conversation.ancestors.clone();
+        tweets.push(conversation.root.clone());
+        tweets.extend(conversation.replies.clone());
+        println!("{}", format::format_rss(&title, &tweets));
+        return Ok(());
+    }
+
+    println!("{}", format::format_thread_terminal(&conversation));
+
+    Ok(())
+}
+
+/// Same reconstruction as `run`, but returns the `Conversation` instead of
+/// printing it, so callers like `repl` can render it through their own
+/// index-tracking cache. `None` means the thread had no ancestors or replies.
+pub async fn run_captured(args: &ThreadArgs, config: &Config, client: &XClient) -> Result<Option<Conversation>> {
+    fetch(args, config, client).await
+}
+
+async fn fetch(args: &ThreadArgs, config: &Config, client: &XClient) -> Result<Option<Conversation>> {
     let token = config.require_bearer_token()?;
 
-    eprintln!("Fetching thread {}...", args.tweet_id);
+    eprintln!("Reconstructing conversation for {}...", args.tweet_id);
 
-    let tweets = twitter::get_thread(client, token, &args.tweet_id, args.pages).await?;
+    let conversation =
+        twitter::get_tweet_thread(client, token, &args.tweet_id, args.depth).await?;
 
     costs::track_cost(
         &config.costs_path(),
         "thread",
-        "/2/tweets/search/recent",
-        tweets.len() as u64,
+        "/2/tweets",
+        conversation.lookups as u64,
     );
 
-    if tweets.is_empty() {
-        println!("No tweets found in thread.");
-        return Ok(());
+    if conversation.ancestors.is_empty() && conversation.replies.is_empty() {
+        return Ok(None);
     }
 
-    println!("\nThread ({} tweets):\n", tweets.len());
-    for (i, t) in tweets.iter().enumerate() {
-        if i > 0 {
-            println!();
-        }
-        println!("{}", format::format_tweet_terminal(t, Some(i), true));
-    }
-
-    Ok(())
+    Ok(Some(conversation))
 }
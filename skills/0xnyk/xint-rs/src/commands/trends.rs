@@ -0,0 +1,238 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use anyhow::Result;
+use whatlang::{detect, Lang};
+
+use crate::api::twitter;
+use crate::cli::TrendsArgs;
+use crate::client::XClient;
+use crate::config::Config;
+use crate::costs;
+use crate::models::Tweet;
+
+/// Tokens shorter than this, or made up entirely of stopwords, are not
+/// interesting enough to rank. Hashtags are kept whatever their length.
+const MIN_TOKEN_LEN: usize = 3;
+
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "that", "this", "with", "from", "have", "was", "are", "you", "your",
+];
+
+/// Used when the user hasn't pointed `--blocklist-file` at anything of
+/// their own.
+const DEFAULT_BLOCKLIST: &[&str] = &["spam", "nsfw"];
+
+/// How much a count from the previous ranking window carries over into the
+/// next one; tuned so a term needs to keep showing up to stay trending
+/// instead of one early burst permanently dominating a long `Watch` run.
+const DECAY_FACTOR: f64 = 0.5;
+
+pub async fn run(args: &TrendsArgs, config: &Config, client: &XClient) -> Result<()> {
+    if args.local {
+        return if args.watch {
+            run_local_watch(args).await
+        } else {
+            run_local(args)
+        };
+    }
+
+    let token = config.require_bearer_token()?;
+    let trends = twitter::get_trends(client, &token, args.location.as_deref()).await?;
+
+    costs::track_cost(&config.costs_path(), "trends", "/2/trends", trends.len() as u64);
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&trends)?);
+    } else {
+        for (i, trend) in trends.iter().take(args.limit).enumerate() {
+            println!("{}. {}", i + 1, trend);
+        }
+    }
+
+    Ok(())
+}
+
+/// Tallies hashtags and significant tokens per detected language across a
+/// corpus of already-fetched tweets, so trend intelligence costs nothing
+/// beyond the tweets the user already paid to fetch.
+fn run_local(args: &TrendsArgs) -> Result<()> {
+    let path = args
+        .from_file
+        .clone()
+        .unwrap_or_else(|| "data/exports/latest.jsonl".to_string());
+
+    let raw = fs::read_to_string(&path)
+        .map_err(|e| anyhow::anyhow!("could not read tweet corpus at {path}: {e}"))?;
+
+    let tweets: Vec<Tweet> = raw
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect();
+
+    let blocklist = load_blocklist(args.blocklist_file.as_deref())?;
+    let mut tracker = LiveTrendTracker::new(blocklist);
+    tracker.record_batch(&tweets);
+
+    for (lang, ranked) in tracker.rank(args.limit) {
+        println!("\n{:?} trends:", lang);
+        for (term, count) in ranked {
+            println!("  {:>5.1}  {}", count, term);
+        }
+    }
+
+    Ok(())
+}
+
+/// Tails `--from-file` on an interval, feeding each newly-appended line
+/// into a `LiveTrendTracker` and re-printing the decayed ranking — the live
+/// counterpart to `run_local`'s one-shot pass, for a corpus a concurrent
+/// `Watch --jsonl` run is continuously appending to.
+async fn run_local_watch(args: &TrendsArgs) -> Result<()> {
+    let path = args
+        .from_file
+        .clone()
+        .unwrap_or_else(|| "data/exports/latest.jsonl".to_string());
+    let interval = parse_interval(&args.watch_interval)?;
+
+    let blocklist = load_blocklist(args.blocklist_file.as_deref())?;
+    let mut tracker = LiveTrendTracker::new(blocklist);
+    let mut offset: u64 = 0;
+
+    loop {
+        let tweets = read_new_tweets(&path, &mut offset)?;
+        if !tweets.is_empty() {
+            tracker.record_batch(&tweets);
+        }
+
+        print!("\x1b[2J\x1b[H"); // clear screen so the ranking reads as "live"
+        for (lang, ranked) in tracker.rank(args.limit) {
+            println!("\n{:?} trends:", lang);
+            for (term, count) in ranked {
+                println!("  {:>5.1}  {}", count, term);
+            }
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Reads whatever's been appended to `path` since `offset`, advancing
+/// `offset` past what was read.
+fn read_new_tweets(path: &str, offset: &mut u64) -> Result<Vec<Tweet>> {
+    let mut file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(_) => return Ok(Vec::new()), // not created yet; try again next interval
+    };
+    file.seek(SeekFrom::Start(*offset))?;
+
+    let mut raw = String::new();
+    let read = file.read_to_string(&mut raw)?;
+    *offset += read as u64;
+
+    Ok(raw
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect())
+}
+
+fn parse_interval(spec: &str) -> Result<Duration> {
+    let spec = spec.trim();
+    let (num, unit) = spec.split_at(spec.len() - 1);
+    let n: u64 = num
+        .parse()
+        .map_err(|_| anyhow::anyhow!("invalid interval `{spec}`, expected e.g. 30s, 1m, 5m"))?;
+    match unit {
+        "s" => Ok(Duration::from_secs(n)),
+        "m" => Ok(Duration::from_secs(n * 60)),
+        "h" => Ok(Duration::from_secs(n * 3600)),
+        _ => anyhow::bail!("invalid interval `{spec}`, expected a suffix of s, m, or h"),
+    }
+}
+
+/// Reads one blocked term per line from `path`, falling back to
+/// `DEFAULT_BLOCKLIST` when no file is configured.
+fn load_blocklist(path: Option<&str>) -> Result<HashSet<String>> {
+    match path {
+        Some(path) => {
+            let raw = fs::read_to_string(path)
+                .map_err(|e| anyhow::anyhow!("could not read blocklist at {path}: {e}"))?;
+            Ok(raw
+                .lines()
+                .map(|l| l.trim().to_lowercase())
+                .filter(|l| !l.is_empty())
+                .collect())
+        }
+        None => Ok(DEFAULT_BLOCKLIST.iter().map(|s| s.to_string()).collect()),
+    }
+}
+
+fn tokenize(text: &str, blocklist: &HashSet<String>) -> Vec<String> {
+    text.split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric() && c != '#').to_lowercase())
+        .filter(|w| w.len() >= MIN_TOKEN_LEN || w.starts_with('#'))
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+        .filter(|w| !blocklist.iter().any(|blocked| w.contains(blocked.as_str())))
+        .collect()
+}
+
+/// Maintains a per-language term tally across a live `Watch` session. Each
+/// call to `rank` both returns the current standings and decays existing
+/// counts, so a `Watch` loop can call `record_batch`/`rank` once per poll
+/// interval (its own time-keyed schedule) and see trends shift as old
+/// bursts fade and new ones appear, without re-reading earlier polls.
+pub struct LiveTrendTracker {
+    counts: HashMap<Lang, HashMap<String, f64>>,
+    blocklist: HashSet<String>,
+}
+
+impl LiveTrendTracker {
+    pub fn new(blocklist: HashSet<String>) -> Self {
+        LiveTrendTracker {
+            counts: HashMap::new(),
+            blocklist,
+        }
+    }
+
+    /// Tallies hashtags/tokens from a freshly-fetched batch of tweets (e.g.
+    /// one `Watch` poll) into the running per-language counts.
+    pub fn record_batch(&mut self, tweets: &[Tweet]) {
+        for tweet in tweets {
+            let Some(info) = detect(&tweet.text) else {
+                continue;
+            };
+            let lang_counts = self.counts.entry(info.lang()).or_default();
+            for token in tokenize(&tweet.text, &self.blocklist) {
+                *lang_counts.entry(token).or_insert(0.0) += 1.0;
+            }
+        }
+    }
+
+    /// Returns the top `limit` terms per language by current (decayed)
+    /// count, then applies `DECAY_FACTOR` to every count so the next
+    /// `record_batch` window starts from a faded baseline rather than an
+    /// ever-growing total.
+    pub fn rank(&mut self, limit: usize) -> Vec<(Lang, Vec<(String, f64)>)> {
+        let mut out = Vec::new();
+        for (lang, lang_counts) in self.counts.iter() {
+            let mut ranked: Vec<(String, f64)> =
+                lang_counts.iter().map(|(term, count)| (term.clone(), *count)).collect();
+            ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+            ranked.truncate(limit);
+            out.push((*lang, ranked));
+        }
+
+        for lang_counts in self.counts.values_mut() {
+            for count in lang_counts.values_mut() {
+                *count *= DECAY_FACTOR;
+            }
+            lang_counts.retain(|_, count| *count > 0.01);
+        }
+
+        out
+    }
+}
@@ -1,25 +1,43 @@
+use std::str::FromStr;
+
 use anyhow::Result;
 
 use crate::api::twitter;
+use crate::cache::{self, TweetCache};
 use crate::cli::TweetArgs;
 use crate::client::XClient;
+use crate::color::{self, ColorMode};
 use crate::config::Config;
 use crate::costs;
 use crate::format;
 
 pub async fn run(args: &TweetArgs, config: &Config, client: &XClient) -> Result<()> {
     let token = config.require_bearer_token()?;
+    let color_mode = ColorMode::from_str(&args.color)?;
+    let colorize = color::should_colorize(color_mode);
+
+    let cache_path = cache::default_cache_path(&config.config_dir());
+    let mut cache = TweetCache::load(&cache_path);
 
-    let tweet = twitter::get_tweet(client, token, &args.tweet_id).await?;
+    let tweet = if let Some(cached) = cache.get_tweet(&args.tweet_id, cache::DEFAULT_TTL_SECS) {
+        Some(cached.clone())
+    } else {
+        let fetched = twitter::get_tweet(client, token, &args.tweet_id).await?;
+        costs::track_cost(&config.costs_path(), "tweet", "/2/tweets", 1);
 
-    costs::track_cost(&config.costs_path(), "tweet", "/2/tweets", 1);
+        if let Some(t) = &fetched {
+            cache.put_tweet(t.clone());
+            cache.save(&cache_path)?;
+        }
+        fetched
+    };
 
     match tweet {
         Some(t) => {
             if args.json {
                 println!("{}", serde_json::to_string_pretty(&t)?);
             } else {
-                println!("{}", format::format_tweet_terminal(&t, None, true));
+                println!("{}", format::format_tweet_terminal(&t, None, true, colorize));
             }
         }
         None => {
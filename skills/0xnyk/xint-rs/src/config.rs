@@ -0,0 +1,138 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::token_pool::{self, TokenPool};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    bearer_tokens: Vec<String>,
+    cached_user_id: Option<String>,
+    pre_post_hook: Option<String>,
+    post_post_hook: Option<String>,
+}
+
+/// Loaded user configuration, plus the directory it lives in so commands can
+/// derive sibling paths (cache, costs ledger, token pool state) from it.
+pub struct Config {
+    dir: PathBuf,
+    file: ConfigFile,
+}
+
+impl Config {
+    pub fn load() -> Result<Self> {
+        let dir = config_dir()?;
+        let mut file = read_config_file(&dir);
+        if let Ok(token) = std::env::var("XINT_BEARER_TOKEN") {
+            if !token.is_empty() && !file.bearer_tokens.contains(&token) {
+                file.bearer_tokens.push(token);
+            }
+        }
+        Ok(Config { dir, file })
+    }
+
+    pub fn config_dir(&self) -> PathBuf {
+        self.dir.clone()
+    }
+
+    pub fn costs_path(&self) -> PathBuf {
+        self.dir.join("costs.json")
+    }
+
+    /// Picks a bearer token for the next request. When more than one token
+    /// is configured, prefers whichever has the most quota left in the
+    /// on-disk token pool, so a single exhausted token doesn't block the
+    /// whole session.
+    pub fn require_bearer_token(&self) -> Result<String> {
+        if self.file.bearer_tokens.is_empty() {
+            bail!("no bearer token configured; run `xint auth setup` or set XINT_BEARER_TOKEN");
+        }
+
+        let pool = self.token_pool();
+        Ok(pool
+            .best_token(now_unix())
+            .map(str::to_string)
+            .unwrap_or_else(|| self.file.bearer_tokens[0].clone()))
+    }
+
+    pub fn bearer_tokens(&self) -> &[String] {
+        &self.file.bearer_tokens
+    }
+
+    pub fn token_pool(&self) -> TokenPool {
+        TokenPool::load(&self.token_pool_path(), &self.file.bearer_tokens)
+    }
+
+    pub fn token_pool_path(&self) -> PathBuf {
+        token_pool::default_pool_path(&self.costs_path())
+    }
+
+    /// Records the rate-limit state `XClient` observed for `token` after a
+    /// request, so the next `require_bearer_token` call rotates away from
+    /// it once it's exhausted.
+    pub fn record_rate_limit(&self, token: &str, remaining: u32, reset_at: i64) -> Result<()> {
+        let path = self.token_pool_path();
+        let mut pool = TokenPool::load(&path, &self.file.bearer_tokens);
+        if remaining == 0 {
+            pool.mark_cooldown(token, reset_at);
+        } else {
+            pool.update_remaining(token, remaining, reset_at);
+        }
+        pool.save(&path)
+    }
+
+    pub fn cached_user_id(&self) -> Option<String> {
+        self.file.cached_user_id.clone()
+    }
+
+    pub fn cache_user_id(&self, id: &str) -> Result<()> {
+        let mut file = read_config_file(&self.dir);
+        file.cached_user_id = Some(id.to_string());
+        write_config_file(&self.dir, &file)
+    }
+
+    pub fn pre_post_hook(&self) -> Option<&str> {
+        self.file.pre_post_hook.as_deref()
+    }
+
+    pub fn post_post_hook(&self) -> Option<&str> {
+        self.file.post_post_hook.as_deref()
+    }
+}
+
+fn config_dir() -> Result<PathBuf> {
+    let dir = dirs_config_dir().join("xint");
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn dirs_config_dir() -> PathBuf {
+    std::env::var_os("XINT_CONFIG_DIR")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+fn read_config_file(dir: &std::path::Path) -> ConfigFile {
+    fs::read_to_string(dir.join("config.json"))
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn write_config_file(dir: &std::path::Path, file: &ConfigFile) -> Result<()> {
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join("config.json"), serde_json::to_string_pretty(file)?)?;
+    Ok(())
+}
+
+pub(crate) fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
@@ -0,0 +1,233 @@
+use crate::api::twitter::Conversation;
+use crate::color::{self, BLUE, BOLD, CYAN, DIM, GREEN};
+use crate::models::{Tweet, User};
+
+/// Renders a single tweet for terminal display. `index` prefixes the line
+/// with a short `[N]` reference (used by `repl`/`search` so follow-up
+/// commands can address the tweet by number instead of its full id).
+/// `show_metrics` controls whether the engagement line is printed at all;
+/// `colorize` gates ANSI codes so piped/`--json` output stays plain.
+pub fn format_tweet_terminal(tweet: &Tweet, index: Option<usize>, show_metrics: bool, colorize: bool) -> String {
+    let mut lines = Vec::new();
+
+    let header = match index {
+        Some(i) => format!(
+            "[{i}] {} {}",
+            color::paint(colorize, BOLD, &format!("{}", tweet.author.name)),
+            color::paint(colorize, DIM, &format!("@{}", tweet.author.username)),
+        ),
+        None => format!(
+            "{} {}",
+            color::paint(colorize, BOLD, &tweet.author.name),
+            color::paint(colorize, DIM, &format!("@{}", tweet.author.username)),
+        ),
+    };
+    lines.push(header);
+
+    if let Some(quoted) = &tweet.quoted {
+        lines.push(highlight_entities(&tweet.text, colorize));
+        lines.push(quote_gutter(quoted, colorize));
+    } else {
+        lines.push(highlight_entities(&tweet.text, colorize));
+    }
+
+    lines.push(color::paint(colorize, DIM, &tweet.created_at));
+
+    if show_metrics {
+        let metrics = format!(
+            "{} likes  {} retweets  {} replies",
+            tweet.metrics.likes, tweet.metrics.retweets, tweet.metrics.replies
+        );
+        lines.push(color::paint(colorize, DIM, &metrics));
+    }
+
+    lines.join("\n")
+}
+
+/// Colors `@mentions` cyan, `#hashtags` blue, and bare URLs green, leaving
+/// everything else untouched.
+fn highlight_entities(text: &str, colorize: bool) -> String {
+    text.split(' ')
+        .map(|word| {
+            if word.starts_with('@') && word.len() > 1 {
+                color::paint(colorize, CYAN, word)
+            } else if word.starts_with('#') && word.len() > 1 {
+                color::paint(colorize, BLUE, word)
+            } else if word.starts_with("http://") || word.starts_with("https://") {
+                color::paint(colorize, GREEN, word)
+            } else {
+                word.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Renders a quoted/retweeted tweet indented under a `>` gutter so it reads
+/// as nested content rather than a second top-level tweet.
+fn quote_gutter(tweet: &Tweet, colorize: bool) -> String {
+    let inner = format_tweet_terminal(tweet, None, false, colorize);
+    inner
+        .lines()
+        .map(|line| format!("{} {line}", color::paint(colorize, DIM, ">")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders a reconstructed conversation as an indented chain: ancestors
+/// (oldest first) leading up to the root tweet, then its direct replies,
+/// each rendered through `format_tweet_terminal` so a quoted/retweeted
+/// tweet nests under its own `>` gutter the same way it would for a single
+/// tweet view — a human reads it top-to-bottom the way the thread happened.
+pub fn format_thread_terminal(conversation: &Conversation) -> String {
+    let mut out = String::new();
+
+    for (depth, ancestor) in conversation.ancestors.iter().enumerate() {
+        out.push_str(&indent(&format_tweet_terminal(ancestor, None, false, false), depth));
+        out.push_str("\n\n");
+    }
+
+    let root_depth = conversation.ancestors.len();
+    out.push_str(&indent(&format_tweet_terminal(&conversation.root, None, true, false), root_depth));
+    out.push_str("\n");
+
+    for reply in &conversation.replies {
+        out.push_str("\n");
+        out.push_str(&indent(&format_tweet_terminal(reply, None, true, false), root_depth + 1));
+        out.push('\n');
+    }
+
+    out
+}
+
+fn indent(text: &str, depth: usize) -> String {
+    let prefix = "  ".repeat(depth);
+    text.lines().map(|line| format!("{prefix}{line}")).collect::<Vec<_>>().join("\n")
+}
+
+/// Renders a plain-text profile view: author line followed by each tweet,
+/// uncolored since this path is also used for `--json`-adjacent piping.
+pub fn format_profile_terminal(user: &User, tweets: &[Tweet]) -> String {
+    let mut out = format!("{} (@{})\n", user.name, user.username);
+    for tweet in tweets {
+        out.push('\n');
+        out.push_str(&format_tweet_terminal(tweet, None, true, false));
+        out.push('\n');
+    }
+    out
+}
+
+/// Renders `tweets` as an RSS 2.0 channel, one `<item>` per tweet, so the
+/// output can be piped straight into a feed reader or static host.
+pub fn format_rss(title: &str, tweets: &[Tweet]) -> String {
+    let mut items = String::new();
+    for tweet in tweets {
+        let link = format!("https://x.com/{}/status/{}", tweet.author.username, tweet.id);
+        let item_title = truncate(&tweet.text, 80);
+        let description = format!(
+            "{} ({} likes, {} retweets, {} replies)",
+            tweet.text, tweet.metrics.likes, tweet.metrics.retweets, tweet.metrics.replies
+        );
+
+        items.push_str(&format!(
+            "  <item>\n    \
+             <title>{}</title>\n    \
+             <link>{link}</link>\n    \
+             <guid>{link}</guid>\n    \
+             <pubDate>{}</pubDate>\n    \
+             <dc:creator>{}</dc:creator>\n    \
+             <description>{}</description>\n  \
+             </item>\n",
+            escape_xml(&item_title),
+            rfc822_date(&tweet.created_at),
+            escape_xml(&tweet.author.username),
+            escape_xml(&description),
+        ));
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <rss version=\"2.0\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n\
+         <channel>\n  \
+         <title>{}</title>\n  \
+         <link>https://x.com</link>\n  \
+         <description>xint feed: {}</description>\n\
+         {items}\
+         </channel>\n\
+         </rss>\n",
+        escape_xml(title),
+        escape_xml(title),
+    )
+}
+
+/// Converts the API's ISO-8601 UTC timestamp (e.g. `2024-01-02T15:04:05.000Z`)
+/// into the RFC-822 format RSS 2.0's `<pubDate>` requires (e.g.
+/// `Tue, 02 Jan 2024 15:04:05 +0000`). Falls back to the original string if it
+/// doesn't parse, so a surprising timestamp shape degrades gracefully instead
+/// of panicking the whole feed.
+fn rfc822_date(created_at: &str) -> String {
+    const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let parse = || -> Option<(i64, u32, u32, u32, u32, u32)> {
+        let date_time = created_at.strip_suffix('Z').unwrap_or(created_at);
+        let (date, time) = date_time.split_once('T')?;
+
+        let mut date_parts = date.splitn(3, '-');
+        let year: i64 = date_parts.next()?.parse().ok()?;
+        let month: u32 = date_parts.next()?.parse().ok()?;
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+        let day: u32 = date_parts.next()?.parse().ok()?;
+
+        let time = time.split('.').next().unwrap_or(time);
+        let mut time_parts = time.splitn(3, ':');
+        let hour: u32 = time_parts.next()?.parse().ok()?;
+        let minute: u32 = time_parts.next()?.parse().ok()?;
+        let second: u32 = time_parts.next()?.parse().ok()?;
+
+        Some((year, month, day, hour, minute, second))
+    };
+
+    let Some((year, month, day, hour, minute, second)) = parse() else {
+        return created_at.to_string();
+    };
+
+    let weekday = WEEKDAYS[sakamoto_weekday(year, month, day)];
+    let month_name = MONTHS.get(month as usize - 1).copied().unwrap_or("Jan");
+
+    format!(
+        "{weekday}, {day:02} {month_name} {year:04} {hour:02}:{minute:02}:{second:02} +0000"
+    )
+}
+
+/// Sakamoto's algorithm for the day of week, returned as an index into
+/// `WEEKDAYS` (0 = Monday, matching ISO 8601's day numbering).
+fn sakamoto_weekday(year: i64, month: u32, day: u32) -> usize {
+    const OFFSETS: [i64; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let mut y = year;
+    if month < 3 {
+        y -= 1;
+    }
+    let dow = (y + y / 4 - y / 100 + y / 400 + OFFSETS[month as usize - 1] + day as i64) % 7;
+    // Sakamoto's formula is 0 = Sunday; rotate so 0 = Monday to index WEEKDAYS.
+    ((dow + 6) % 7) as usize
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        format!("{}…", text.chars().take(max_chars).collect::<String>())
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
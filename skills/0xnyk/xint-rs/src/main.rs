@@ -2,6 +2,7 @@ mod api;
 mod auth;
 mod cache;
 mod cli;
+mod color;
 mod client;
 mod commands;
 mod config;
@@ -10,6 +11,7 @@ mod format;
 mod models;
 mod sentiment;
 mod mcp;
+mod token_pool;
 
 use anyhow::Result;
 use clap::Parser;
@@ -31,6 +33,9 @@ async fn main() -> Result<()> {
         Some(Commands::Watch(args)) => {
             commands::watch::run(&args, &config, &client).await?;
         }
+        Some(Commands::Stream(args)) => {
+            commands::stream::run(&args, &config, &client).await?;
+        }
         Some(Commands::Diff(args)) => {
             commands::diff::run(&args, &config, &client).await?;
         }
@@ -70,6 +75,27 @@ async fn main() -> Result<()> {
         Some(Commands::Following(args)) => {
             commands::engagement::run_following(&args, &config, &client).await?;
         }
+        Some(Commands::Follow(args)) => {
+            commands::engagement::run_follow(&args, &config, &client).await?;
+        }
+        Some(Commands::Unfollow(args)) => {
+            commands::engagement::run_unfollow(&args, &config, &client).await?;
+        }
+        Some(Commands::Post(args)) => {
+            commands::engagement::run_post(&args, &config, &client).await?;
+        }
+        Some(Commands::Reply(args)) => {
+            commands::engagement::run_reply(&args, &config, &client).await?;
+        }
+        Some(Commands::Quote(args)) => {
+            commands::engagement::run_quote(&args, &config, &client).await?;
+        }
+        Some(Commands::Retweet(args)) => {
+            commands::engagement::run_retweet(&args, &config, &client).await?;
+        }
+        Some(Commands::Unretweet(args)) => {
+            commands::engagement::run_unretweet(&args, &config, &client).await?;
+        }
         Some(Commands::Trends(args)) => {
             commands::trends::run(&args, &config, &client).await?;
         }
@@ -97,6 +123,9 @@ async fn main() -> Result<()> {
         Some(Commands::Mcp(args)) => {
             mcp::run(args).await?;
         }
+        Some(Commands::Repl(args)) => {
+            commands::repl::run(&args, &config, &client).await?;
+        }
         None => {
             // Show help when no command provided
             use clap::CommandFactory;
@@ -0,0 +1,110 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Rate-limit window for a single bearer token, as last observed from the
+/// `x-rate-limit-remaining` / `x-rate-limit-reset` response headers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenState {
+    pub token: String,
+    pub remaining: u32,
+    /// Unix timestamp the window resets at; `0` while unknown.
+    pub reset_at: i64,
+}
+
+impl TokenState {
+    fn fresh(token: &str) -> Self {
+        TokenState {
+            token: token.to_string(),
+            remaining: u32::MAX,
+            reset_at: 0,
+        }
+    }
+
+    fn is_cooling_down(&self, now: i64) -> bool {
+        self.remaining == 0 && self.reset_at > now
+    }
+}
+
+/// Tracks remaining-quota state across a pool of bearer tokens so `Config`
+/// can rotate to whichever one has headroom instead of the caller juggling
+/// tokens by hand.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TokenPool {
+    tokens: Vec<TokenState>,
+}
+
+impl TokenPool {
+    /// Loads persisted window state from `path`, seeding any tokens in
+    /// `known` that haven't been seen before.
+    pub fn load(path: &Path, known: &[String]) -> Self {
+        let mut pool: TokenPool = fs::read_to_string(path)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default();
+
+        for token in known {
+            if !pool.tokens.iter().any(|t| &t.token == token) {
+                pool.tokens.push(TokenState::fresh(token));
+            }
+        }
+        pool
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Picks the token with the most remaining quota that isn't currently
+    /// cooling down.
+    pub fn best_token(&self, now: i64) -> Option<&str> {
+        self.tokens
+            .iter()
+            .filter(|t| !t.is_cooling_down(now))
+            .max_by_key(|t| t.remaining)
+            .map(|t| t.token.as_str())
+    }
+
+    /// Records a 429 (or an exhausted header) against `token`, putting it
+    /// into cooldown until `reset_at`.
+    pub fn mark_cooldown(&mut self, token: &str, reset_at: i64) {
+        if let Some(state) = self.tokens.iter_mut().find(|t| t.token == token) {
+            state.remaining = 0;
+            state.reset_at = reset_at;
+        }
+    }
+
+    pub fn update_remaining(&mut self, token: &str, remaining: u32, reset_at: i64) {
+        if let Some(state) = self.tokens.iter_mut().find(|t| t.token == token) {
+            state.remaining = remaining;
+            state.reset_at = reset_at;
+        }
+    }
+
+    /// Summary used by the `Costs` command to surface pool health.
+    pub fn health_report(&self, now: i64) -> String {
+        let mut lines = vec![format!("Token pool ({} tokens):", self.tokens.len())];
+        for state in &self.tokens {
+            let status = if state.is_cooling_down(now) {
+                format!("cooling down until {}", state.reset_at)
+            } else {
+                format!("{} remaining", state.remaining)
+            };
+            lines.push(format!("  {}...  {}", &state.token[..state.token.len().min(8)], status));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Default location for the pool state file, kept next to the costs ledger.
+pub fn default_pool_path(costs_path: &Path) -> PathBuf {
+    costs_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join("token_pool.json")
+}